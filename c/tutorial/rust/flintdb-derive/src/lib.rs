@@ -0,0 +1,111 @@
+//! `#[derive(FlintRow)]` — generates `ToRow`/`FromRow` impls that map a
+//! struct's fields onto FlintDB columns by name, so callers can do
+//! `tbl.apply_row(&mut mt, &customer)` / `let c: Customer = row.into_struct(&mut mt)?`
+//! instead of writing out positional `set_*`/`get_*` calls by hand.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+#[proc_macro_derive(FlintRow)]
+pub fn derive_flint_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    &input,
+                    "FlintRow can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into();
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(&input, "FlintRow can only be derived for structs")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let mut to_row_arms = Vec::new();
+    let mut from_row_arms = Vec::new();
+
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let column_name = ident.to_string();
+        let ty = &field.ty;
+
+        let (setter, getter, set_arg) = match type_name(ty).as_deref() {
+            Some("i32") => (quote! { set_i32 }, quote! { get_i32 }, quote! { self.#ident }),
+            Some("i64") => (quote! { set_i64 }, quote! { get_i64 }, quote! { self.#ident }),
+            Some("f64") => (quote! { set_f64 }, quote! { get_f64 }, quote! { self.#ident }),
+            Some("String") => (
+                quote! { set_string },
+                quote! { get_string },
+                quote! { self.#ident.as_str() },
+            ),
+            _ => {
+                return syn::Error::new_spanned(
+                    ty,
+                    format!(
+                        "FlintRow does not know how to map field `{}` of this type; \
+                         supported field types are i32, i64, f64, and String",
+                        ident
+                    ),
+                )
+                .to_compile_error()
+                .into();
+            }
+        };
+
+        to_row_arms.push(quote! {
+            {
+                let col = flintdb::get_column_index(mt, #column_name);
+                row.#setter(col, #set_arg)
+                    .map_err(|e| format!("column `{}`: {}", #column_name, e))?;
+            }
+        });
+
+        from_row_arms.push(quote! {
+            #ident: {
+                let col = flintdb::get_column_index(mt, #column_name);
+                row.#getter(col)
+                    .map_err(|e| format!("column `{}`: {}", #column_name, e))?
+            }
+        });
+    }
+
+    let expanded = quote! {
+        impl flintdb::ToRow for #name {
+            fn to_row(&self, mt: &mut flintdb::Meta) -> Result<flintdb::Row<'static>, String> {
+                let mut row = flintdb::Row::new(mt)?;
+                #(#to_row_arms)*
+                Ok(row)
+            }
+        }
+
+        impl flintdb::FromRow for #name {
+            fn from_row(row: &flintdb::Row<'_>, mt: &mut flintdb::Meta) -> Result<Self, String> {
+                Ok(#name {
+                    #(#from_row_arms),*
+                })
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+// Resolves a field's type to the simple name we dispatch setters/getters
+// on (e.g. `i32`, `String`), ignoring path qualification.
+fn type_name(ty: &syn::Type) -> Option<String> {
+    if let syn::Type::Path(type_path) = ty {
+        type_path.path.segments.last().map(|seg| seg.ident.to_string())
+    } else {
+        None
+    }
+}
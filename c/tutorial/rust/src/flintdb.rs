@@ -6,8 +6,14 @@
 include!("bindings.rs");
 
 use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
 use std::ptr;
 
+/// `#[derive(FlintRow)]` generates `ToRow`/`FromRow` for a struct by
+/// resolving each field to a column index once and dispatching to the
+/// matching typed setter/getter. See the `flintdb-derive` crate.
+pub use flintdb_derive::FlintRow;
+
 // Type aliases for shorter names
 #[allow(unused_imports)]
 pub use flintdb_variant_type_VARIANT_NULL as VAR_NULL;
@@ -135,6 +141,31 @@ impl Meta {
         Ok(sql.to_string_lossy().into_owned())
     }
 
+    pub fn column_count(&self) -> Result<u16, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let count = unsafe { flintdb_meta_column_count(&self.inner, &mut err) };
+        check_error(err)?;
+        Ok(count as u16)
+    }
+
+    pub fn column_name(&self, col: u16) -> Result<String, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let name_ptr = unsafe { flintdb_meta_column_name(&self.inner, col, &mut err) };
+        check_error(err)?;
+        if name_ptr.is_null() {
+            return Err(format!("No column at index {}", col));
+        }
+        let c_str = unsafe { CStr::from_ptr(name_ptr) };
+        Ok(c_str.to_string_lossy().into_owned())
+    }
+
+    pub fn column_type(&self, col: u16) -> Result<flintdb_variant_type, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let vtype = unsafe { flintdb_meta_column_type(&self.inner, col, &mut err) };
+        check_error(err)?;
+        Ok(vtype)
+    }
+
     pub fn as_ptr(&self) -> *const flintdb_meta {
         &self.inner as *const flintdb_meta
     }
@@ -156,6 +187,7 @@ impl Drop for Meta {
 
 pub struct Table {
     ptr: *mut flintdb_table,
+    path: String,
 }
 
 impl Table {
@@ -168,7 +200,10 @@ impl Table {
         if ptr.is_null() {
             return Err("Failed to open table".to_string());
         }
-        Ok(Table { ptr })
+        Ok(Table {
+            ptr,
+            path: flintdb_tablename.to_string(),
+        })
     }
 
     pub fn drop_table(flintdb_tablename: &str) -> Result<(), String> {
@@ -178,7 +213,7 @@ impl Table {
         check_error(err)
     }
 
-    pub fn apply(&mut self, row: &mut Row) -> Result<i64, String> {
+    pub fn apply(&mut self, row: &mut Row<'_>) -> Result<i64, String> {
         let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
         let tbl = unsafe { &mut *self.ptr };
         let rowid = unsafe {
@@ -192,7 +227,72 @@ impl Table {
         Ok(rowid)
     }
 
-    pub fn read(&self, rowid: i64) -> Result<Row, String> {
+    /// Overwrite the row at `rowid` with the contents of `row`.
+    pub fn update(&mut self, rowid: i64, row: &mut Row<'_>) -> Result<(), String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let tbl = unsafe { &mut *self.ptr };
+        unsafe {
+            if let Some(apply_at_fn) = tbl.apply_at {
+                apply_at_fn(self.ptr, row.ptr, rowid, &mut err);
+            } else {
+                return Err("apply_at function not available".to_string());
+            }
+        }
+        check_error(err)
+    }
+
+    /// Delete the row at `rowid`.
+    pub fn delete(&mut self, rowid: i64) -> Result<(), String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let tbl = unsafe { &mut *self.ptr };
+        unsafe {
+            if let Some(delete_fn) = tbl.delete {
+                delete_fn(self.ptr, rowid, &mut err);
+            } else {
+                return Err("delete function not available".to_string());
+            }
+        }
+        check_error(err)
+    }
+
+    /// Return rowids ordered by `order_by`, e.g.
+    /// `tbl.filesort(&[("age", SortOrder::Desc), ("name", SortOrder::Asc)])`.
+    pub fn filesort(&mut self, order_by: &[(&str, SortOrder)]) -> Result<CursorI64, String> {
+        let mut c_keys: Vec<[::std::os::raw::c_char; 40]> = Vec::new();
+        let mut directions: Vec<i32> = Vec::new();
+        for (key, order) in order_by {
+            let mut arr = [0 as ::std::os::raw::c_char; 40];
+            let c_key = CString::new(*key).unwrap();
+            let bytes = c_key.as_bytes_with_nul();
+            let len = bytes.len().min(40);
+            arr[..len].copy_from_slice(unsafe { std::mem::transmute(&bytes[..len]) });
+            c_keys.push(arr);
+            directions.push(order.as_raw());
+        }
+
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let tbl = unsafe { &mut *self.ptr };
+        let ptr = unsafe {
+            if let Some(filesort_fn) = tbl.filesort {
+                filesort_fn(
+                    self.ptr,
+                    c_keys.as_ptr(),
+                    directions.as_ptr(),
+                    order_by.len() as u16,
+                    &mut err,
+                )
+            } else {
+                return Err("filesort function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        if ptr.is_null() {
+            return Err("Failed to create cursor".to_string());
+        }
+        Ok(CursorI64 { ptr })
+    }
+
+    pub fn read(&self, rowid: i64) -> Result<Row<'_>, String> {
         let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
         let tbl = unsafe { &*self.ptr };
         let row_ptr = unsafe {
@@ -206,7 +306,8 @@ impl Table {
         if row_ptr.is_null() {
             return Err("Failed to read row".to_string());
         }
-        // read() returns a borrowed row owned by the table
+        // read() returns a row owned by the table's internal buffer; its
+        // lifetime is tied to this `&Table` so it can't outlive the table.
         Ok(unsafe { Row::borrowed(row_ptr as *mut flintdb_row) })
     }
 
@@ -227,6 +328,44 @@ impl Table {
         }
         Ok(CursorI64 { ptr })
     }
+
+    /// Convert `value` to a `Row` via its `ToRow` impl and insert it,
+    /// saving the caller a manual positional `set_*` dance.
+    pub fn apply_row<T: ToRow>(&mut self, mt: &mut Meta, value: &T) -> Result<i64, String> {
+        let mut row = value.to_row(mt)?;
+        self.apply(&mut row)
+    }
+
+    /// Write a copy of this table's file to `dest_path`, reporting
+    /// progress in page-sized steps through `progress` if given. Uses the
+    /// C core's native snapshot primitive when available, which is atomic
+    /// with respect to concurrent writers. Otherwise falls back to a
+    /// page-by-page copy, held under the table's native read lock for the
+    /// duration when the core exposes one (`rlock`/`runlock`); without
+    /// that primitive the fallback is best-effort only, and a concurrent
+    /// `apply`/`update`/`delete` can still interleave a torn mix of old
+    /// and new pages into `dest_path`.
+    pub fn backup(
+        &self,
+        dest_path: &str,
+        progress: Option<&mut dyn FnMut(u64, u64)>,
+    ) -> Result<(), String> {
+        let c_dest = CString::new(dest_path).unwrap();
+        let tbl = unsafe { &*self.ptr };
+        if let Some(backup_fn) = tbl.backup {
+            let mut progress = progress;
+            let userdata =
+                &mut progress as *mut Option<&mut dyn FnMut(u64, u64)> as *mut ::std::os::raw::c_void;
+            let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+            unsafe { backup_fn(self.ptr, c_dest.as_ptr(), Some(backup_progress_trampoline), userdata, &mut err) };
+            return check_error(err);
+        }
+        // No native snapshot primitive in this build of the C core: fall
+        // back to a chunked copy of the table's own file through its
+        // path, holding the native read lock (if any) for the duration.
+        let _guard = TableReadGuard::acquire(self)?;
+        chunked_copy(&self.path, dest_path, progress)
+    }
 }
 
 impl Drop for Table {
@@ -239,31 +378,168 @@ impl Drop for Table {
     }
 }
 
+/// On-disk record format for a `GenericFile`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Format {
+    /// The C core's native tab-separated format.
+    Tsv,
+    /// Comma- (or other-) separated values, with quoting for fields that
+    /// contain the delimiter, the quote character, or a `\n`/`\r`.
+    Csv { delimiter: char, quote: char },
+    /// One JSON object per line, keyed by column name.
+    JsonLines,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Format::Tsv
+    }
+}
+
+impl Format {
+    // Split `content` into its records (rows of raw field text), in a
+    // format-specific way. The native Tsv format never takes this path
+    // since it's always handled by the C core directly. `columns` is the
+    // schema snapshotted at open time; JsonLines uses it to validate that
+    // each line's keys actually match, rather than trusting position alone.
+    fn decode_records(
+        &self,
+        content: &str,
+        columns: &[(String, flintdb_variant_type)],
+    ) -> Result<Vec<Vec<String>>, String> {
+        match self {
+            Format::Tsv => Ok(Vec::new()),
+            Format::Csv { delimiter, quote } => Ok(split_csv_records(content, *delimiter, *quote)),
+            Format::JsonLines => content
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| parse_json_object_line(line, columns))
+                .collect(),
+        }
+    }
+
+    // Render one record as a line of the external on-disk format.
+    fn encode_record(
+        &self,
+        columns: &[(String, flintdb_variant_type)],
+        values: &[Value],
+    ) -> Result<String, String> {
+        match self {
+            Format::Tsv => Err("Tsv records are written by the native engine directly".to_string()),
+            Format::Csv { delimiter, quote } => {
+                let fields: Vec<String> = values
+                    .iter()
+                    .map(|v| csv_encode_field(&value_to_text(v)?, *delimiter, *quote))
+                    .collect::<Result<_, String>>()?;
+                Ok(fields.join(&delimiter.to_string()))
+            }
+            Format::JsonLines => {
+                let fields: Vec<String> = columns
+                    .iter()
+                    .zip(values)
+                    .map(|((name, _), value)| format!("{}:{}", json_encode_string(name), json_encode_value(value)))
+                    .collect();
+                Ok(format!("{{{}}}", fields.join(",")))
+            }
+        }
+    }
+}
+
 pub struct GenericFile {
+    // Always a native, TSV-backed handle: for `Format::Tsv` this is the
+    // file the caller asked for; for `Csv`/`JsonLines` it's a hidden
+    // mirror used so `find`'s WHERE-clause cursor can be reused as-is.
     ptr: *mut flintdb_genericfile,
+    path: String,
+    format: Format,
+    // Column names/types snapshotted from `Meta` at open time, needed to
+    // serialize/parse non-native formats without threading a `&Meta`
+    // through every `write`/`find` call.
+    columns: Vec<(String, flintdb_variant_type)>,
+    // The mode this file was opened with, so `write` can reject itself
+    // for `Csv`/`JsonLines` the same way the native engine already does
+    // for `Tsv`.
+    mode: flintdb_open_mode,
 }
 
 impl GenericFile {
-    pub fn open(filepath: &str, mode: flintdb_open_mode, mt: Option<&Meta>) -> Result<Self, String> {
-        let c_filepath = CString::new(filepath).unwrap();
+    pub fn open(
+        filepath: &str,
+        mode: flintdb_open_mode,
+        mt: Option<&mut Meta>,
+        format: Format,
+    ) -> Result<Self, String> {
+        if format == Format::Tsv {
+            let c_filepath = CString::new(filepath).unwrap();
+            let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+            let flintdb_meta_ptr = mt.map_or(ptr::null(), |m| m.as_ptr());
+            let ptr = unsafe { flintdb_genericfile_open(c_filepath.as_ptr(), mode, flintdb_meta_ptr, &mut err) };
+            check_error(err)?;
+            if ptr.is_null() {
+                return Err("Failed to open file".to_string());
+            }
+            return Ok(GenericFile {
+                ptr,
+                path: filepath.to_string(),
+                format,
+                columns: Vec::new(),
+                mode,
+            });
+        }
+
+        // Csv/JsonLines need the schema up front, both to serialize rows
+        // and to stand up the native mirror that backs `find`.
+        let mt = mt.ok_or_else(|| "Csv/JsonLines formats require a Meta".to_string())?;
+        let columns = read_columns(mt)?;
+
+        let mirror_path = format!("{}.native", filepath);
+        let c_mirror_path = CString::new(mirror_path.as_str()).unwrap();
+
+        // The mirror is about to be rebuilt below from `filepath`'s
+        // current content, so drop any stale mirror left over from a
+        // previous open instead of reopening (and replaying on top of)
+        // it - otherwise every reopen of the same file would duplicate
+        // its rows. The mirror handle itself always needs RDWR to do
+        // this rebuild, regardless of the caller's requested `mode`;
+        // `write` below is what actually enforces `mode` against the
+        // caller-visible file.
+        let _ = GenericFile::drop_file(&mirror_path);
         let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
-        let flintdb_meta_ptr = mt.map_or(ptr::null(), |m| m.as_ptr());
-        let ptr = unsafe { flintdb_genericfile_open(c_filepath.as_ptr(), mode, flintdb_meta_ptr, &mut err) };
+        let ptr = unsafe {
+            flintdb_genericfile_open(c_mirror_path.as_ptr(), flintdb_open_mode_FLINTDB_RDWR, mt.as_ptr(), &mut err)
+        };
         check_error(err)?;
         if ptr.is_null() {
-            return Err("Failed to open file".to_string());
+            return Err("Failed to open native mirror file".to_string());
         }
-        Ok(GenericFile { ptr })
-    }
 
-    pub fn drop_file(filepath: &str) -> Result<(), String> {
-        let c_filepath = CString::new(filepath).unwrap();
-        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
-        unsafe { flintdb_genericfile_drop(c_filepath.as_ptr(), &mut err) };
-        check_error(err)
+        let mut file = GenericFile {
+            ptr,
+            path: filepath.to_string(),
+            format,
+            columns,
+            mode,
+        };
+
+        // Load any existing on-disk content into the mirror so `find`
+        // sees it immediately.
+        if let Ok(existing) = std::fs::read_to_string(filepath) {
+            for fields in file.format.decode_records(&existing, &file.columns)? {
+                let mut row = Row::new(&mut *mt)?;
+                for (col, (_, vtype)) in file.columns.iter().enumerate() {
+                    if let Some(raw) = fields.get(col) {
+                        let value = value_from_text(raw, *vtype)?;
+                        row.set_variant(col as u16, &value)?;
+                    }
+                }
+                file.native_write(&mut row)?;
+            }
+        }
+
+        Ok(file)
     }
 
-    pub fn write(&mut self, row: &mut Row) -> Result<(), String> {
+    fn native_write(&mut self, row: &mut Row<'_>) -> Result<(), String> {
         let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
         let f = unsafe { &mut *self.ptr };
         let result = unsafe {
@@ -280,6 +556,83 @@ impl GenericFile {
         Ok(())
     }
 
+    /// Write a copy of this file to `dest_path`, reporting progress in
+    /// page-sized steps through `progress` if given. Uses the C core's
+    /// native snapshot primitive when available, which is atomic with
+    /// respect to concurrent writers. Otherwise falls back to a
+    /// page-by-page copy, held under the file's native read lock for the
+    /// duration when the core exposes one (`rlock`/`runlock`); without
+    /// that primitive the fallback is best-effort only, and a concurrent
+    /// `write` can still interleave a torn mix of old and new pages into
+    /// `dest_path`. For `Csv`/`JsonLines`, whose native handle is the
+    /// hidden mirror, the page-by-page copy of the on-disk file is always
+    /// used. See `Table::backup` for the same tradeoffs over a table file.
+    pub fn backup(
+        &self,
+        dest_path: &str,
+        progress: Option<&mut dyn FnMut(u64, u64)>,
+    ) -> Result<(), String> {
+        let c_dest = CString::new(dest_path).unwrap();
+        let f = unsafe { &*self.ptr };
+        if let (Format::Tsv, Some(backup_fn)) = (&self.format, f.backup) {
+            let mut progress = progress;
+            let userdata =
+                &mut progress as *mut Option<&mut dyn FnMut(u64, u64)> as *mut ::std::os::raw::c_void;
+            let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+            unsafe { backup_fn(self.ptr, c_dest.as_ptr(), Some(backup_progress_trampoline), userdata, &mut err) };
+            return check_error(err);
+        }
+        // No native snapshot primitive in this build of the C core: fall
+        // back to a chunked copy of the file's own on-disk path, holding
+        // the native read lock (if any) for the duration.
+        let _guard = GenericFileReadGuard::acquire(self)?;
+        chunked_copy(&self.path, dest_path, progress)
+    }
+
+    pub fn drop_file(filepath: &str) -> Result<(), String> {
+        let c_filepath = CString::new(filepath).unwrap();
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        unsafe { flintdb_genericfile_drop(c_filepath.as_ptr(), &mut err) };
+        check_error(err)?;
+
+        // Also drop the hidden native mirror that Csv/JsonLines opens
+        // stand up alongside `filepath` - best-effort, since Tsv files
+        // never have one.
+        let mirror_path = format!("{}.native", filepath);
+        let c_mirror_path = CString::new(mirror_path).unwrap();
+        let mut mirror_err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        unsafe { flintdb_genericfile_drop(c_mirror_path.as_ptr(), &mut mirror_err) };
+        let _ = check_error(mirror_err);
+
+        Ok(())
+    }
+
+    pub fn write(&mut self, row: &mut Row<'_>) -> Result<(), String> {
+        if self.mode == flintdb_open_mode_FLINTDB_RDONLY {
+            return Err("Cannot write: file was opened read-only".to_string());
+        }
+        if self.format == Format::Tsv {
+            return self.native_write(row);
+        }
+
+        // Translate the row to the external on-disk representation...
+        let mut values = Vec::with_capacity(self.columns.len());
+        for col in 0..self.columns.len() as u16 {
+            values.push(row.get_variant(col)?);
+        }
+        let line = self.format.encode_record(&self.columns, &values)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| e.to_string())?;
+        use std::io::Write;
+        writeln!(file, "{}", line).map_err(|e| e.to_string())?;
+
+        // ...and mirror it into the native engine backing `find`.
+        self.native_write(row)
+    }
+
     pub fn find(&mut self, where_clause: &str) -> Result<CursorRow, String> {
         let c_where = CString::new(where_clause).unwrap();
         let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
@@ -309,12 +662,25 @@ impl Drop for GenericFile {
     }
 }
 
-pub struct Row {
+// Tracks who is responsible for freeing the underlying `flintdb_row`.
+enum Ownership {
+    Owned,
+    Borrowed,
+}
+
+/// A row whose validity is tied to the `'a` lifetime of the `Table`,
+/// `CursorRow`, or other owner that the underlying C memory belongs to.
+/// Rows created with `Row::new` own their memory and are `'static`; rows
+/// handed back by `Table::read` or `CursorRow::next` borrow from the
+/// owner that produced them, so the borrow checker rejects keeping them
+/// alive past that owner's lifetime.
+pub struct Row<'a> {
     pub ptr: *mut flintdb_row,
-    owned: bool,  // true if we own the row and should free it
+    ownership: Ownership,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl Row {
+impl Row<'static> {
     pub fn new(mt: &mut Meta) -> Result<Self, String> {
         let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
         let ptr = unsafe { flintdb_row_new(mt.as_mut_ptr(), &mut err) };
@@ -322,12 +688,23 @@ impl Row {
         if ptr.is_null() {
             return Err("Failed to create row".to_string());
         }
-        Ok(Row { ptr, owned: true })
+        Ok(Row {
+            ptr,
+            ownership: Ownership::Owned,
+            _marker: PhantomData,
+        })
     }
-    
-    // Create a borrowed row (not owned, won't be freed)
+}
+
+impl<'a> Row<'a> {
+    // Create a row borrowing from whichever owner produced `ptr`; it
+    // won't be freed when this `Row` is dropped.
     unsafe fn borrowed(ptr: *mut flintdb_row) -> Self {
-        Row { ptr, owned: false }
+        Row {
+            ptr,
+            ownership: Ownership::Borrowed,
+            _marker: PhantomData,
+        }
     }
 
     pub fn set_i32(&mut self, col: u16, value: i32) -> Result<(), String> {
@@ -378,12 +755,248 @@ impl Row {
         }
         check_error(err)
     }
+
+    pub fn set_bytes(&mut self, col: u16, value: &[u8]) -> Result<(), String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        unsafe {
+            if let Some(set_fn) = (*self.ptr).bytes_set {
+                set_fn(self.ptr, col, value.as_ptr(), value.len() as i32, &mut err);
+            } else {
+                return Err("bytes_set function not available".to_string());
+            }
+        }
+        check_error(err)
+    }
+
+    pub fn is_null(&self, col: u16) -> Result<bool, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let result = unsafe {
+            if let Some(is_null_fn) = (*self.ptr).is_null {
+                is_null_fn(self.ptr, col, &mut err)
+            } else {
+                return Err("is_null function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        Ok(result != 0)
+    }
+
+    pub fn get_i32(&self, col: u16) -> Result<i32, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let value = unsafe {
+            if let Some(get_fn) = (*self.ptr).i32_get {
+                get_fn(self.ptr, col, &mut err)
+            } else {
+                return Err("i32_get function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        Ok(value)
+    }
+
+    pub fn get_i64(&self, col: u16) -> Result<i64, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let value = unsafe {
+            if let Some(get_fn) = (*self.ptr).i64_get {
+                get_fn(self.ptr, col, &mut err)
+            } else {
+                return Err("i64_get function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        Ok(value)
+    }
+
+    pub fn get_f64(&self, col: u16) -> Result<f64, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let value = unsafe {
+            if let Some(get_fn) = (*self.ptr).f64_get {
+                get_fn(self.ptr, col, &mut err)
+            } else {
+                return Err("f64_get function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        Ok(value)
+    }
+
+    pub fn get_string(&self, col: u16) -> Result<String, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let value_ptr = unsafe {
+            if let Some(get_fn) = (*self.ptr).string_get {
+                get_fn(self.ptr, col, &mut err)
+            } else {
+                return Err("string_get function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        if value_ptr.is_null() {
+            return Ok(String::new());
+        }
+        let c_str = unsafe { CStr::from_ptr(value_ptr) };
+        Ok(c_str.to_string_lossy().into_owned())
+    }
+
+    pub fn get_bytes(&self, col: u16) -> Result<Vec<u8>, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let mut len: i32 = 0;
+        let value_ptr = unsafe {
+            if let Some(get_fn) = (*self.ptr).bytes_get {
+                get_fn(self.ptr, col, &mut len, &mut err)
+            } else {
+                return Err("bytes_get function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        if value_ptr.is_null() || len <= 0 {
+            return Ok(Vec::new());
+        }
+        Ok(unsafe { std::slice::from_raw_parts(value_ptr, len as usize) }.to_vec())
+    }
+
+    /// Read column `col` as a `chrono::NaiveDate`, decoded from the
+    /// underlying days-since-epoch representation.
+    #[cfg(feature = "chrono")]
+    pub fn get_date(&self, col: u16) -> Result<chrono::NaiveDate, String> {
+        let days = self.get_i64(col)?;
+        chrono::NaiveDate::from_ymd_opt(1970, 1, 1)
+            .unwrap()
+            .checked_add_signed(chrono::Duration::days(days))
+            .ok_or_else(|| "Date out of range".to_string())
+    }
+
+    /// Read column `col` as a `chrono::NaiveTime`, decoded from the
+    /// underlying seconds-since-midnight representation.
+    #[cfg(feature = "chrono")]
+    pub fn get_time(&self, col: u16) -> Result<chrono::NaiveTime, String> {
+        let secs = self.get_i64(col)?;
+        chrono::NaiveTime::from_num_seconds_from_midnight_opt(secs as u32, 0)
+            .ok_or_else(|| "Time out of range".to_string())
+    }
+
+    /// Read column `col` as a `uuid::Uuid`, decoded from its 16-byte form.
+    #[cfg(feature = "uuid")]
+    pub fn get_uuid(&self, col: u16) -> Result<uuid::Uuid, String> {
+        let bytes = self.get_bytes(col)?;
+        uuid::Uuid::from_slice(&bytes).map_err(|e| e.to_string())
+    }
+
+    /// Read column `col` as a `rust_decimal::Decimal`.
+    #[cfg(feature = "rust_decimal")]
+    pub fn get_decimal(&self, col: u16) -> Result<rust_decimal::Decimal, String> {
+        use std::str::FromStr;
+        let text = self.get_string(col)?;
+        rust_decimal::Decimal::from_str(&text).map_err(|e| e.to_string())
+    }
+
+    /// Read column `col` as a `std::net::Ipv6Addr`, decoded from its
+    /// 16-byte form.
+    pub fn get_ipv6(&self, col: u16) -> Result<std::net::Ipv6Addr, String> {
+        let bytes = self.get_bytes(col)?;
+        let octets: [u8; 16] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| "Expected 16 bytes for IPv6 address".to_string())?;
+        Ok(std::net::Ipv6Addr::from(octets))
+    }
+
+    fn variant_type(&self, col: u16) -> Result<flintdb_variant_type, String> {
+        let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+        let vtype = unsafe {
+            if let Some(type_fn) = (*self.ptr).variant_type_get {
+                type_fn(self.ptr, col, &mut err)
+            } else {
+                return Err("variant_type_get function not available".to_string());
+            }
+        };
+        check_error(err)?;
+        Ok(vtype)
+    }
+
+    /// Read column `col` as the untyped `Value` fallback, dispatching on
+    /// the column's declared VARIANT type.
+    pub fn get_variant(&self, col: u16) -> Result<Value, String> {
+        if self.is_null(col)? {
+            return Ok(Value::Null);
+        }
+        match self.variant_type(col)? {
+            VAR_INT32 => Ok(Value::I32(self.get_i32(col)?)),
+            VAR_INT64 => Ok(Value::I64(self.get_i64(col)?)),
+            VAR_DOUBLE => Ok(Value::F64(self.get_f64(col)?)),
+            VAR_STRING => Ok(Value::String(self.get_string(col)?)),
+            VAR_BYTES | VAR_BLOB => Ok(Value::Bytes(self.get_bytes(col)?)),
+            VAR_IPV6 => Ok(Value::Ipv6(self.get_ipv6(col)?)),
+            #[cfg(feature = "chrono")]
+            VAR_DATE => Ok(Value::Date(self.get_date(col)?)),
+            #[cfg(feature = "chrono")]
+            VAR_TIME => Ok(Value::Time(self.get_time(col)?)),
+            #[cfg(feature = "uuid")]
+            VAR_UUID => Ok(Value::Uuid(self.get_uuid(col)?)),
+            #[cfg(feature = "rust_decimal")]
+            VAR_DECIMAL => Ok(Value::Decimal(self.get_decimal(col)?)),
+            other => Err(format!("Unsupported variant type: {}", other)),
+        }
+    }
+
+    /// Convert this row into a `T` via `T`'s `FromRow` impl, typically
+    /// generated by `#[derive(FlintRow)]`.
+    pub fn into_struct<T: FromRow>(&self, mt: &mut Meta) -> Result<T, String> {
+        T::from_row(self, mt)
+    }
+
+    /// Set column `col` from the untyped `Value` fallback, used by
+    /// `GenericFile`'s pluggable record formats to fill in a row from a
+    /// parsed CSV/JSON Lines field. Only `I32`, `I64`, `F64` and `String`
+    /// are written; `Null` leaves the column as it is, and any other
+    /// variant returns an error.
+    pub fn set_variant(&mut self, col: u16, value: &Value) -> Result<(), String> {
+        match value {
+            Value::Null => Ok(()),
+            Value::I32(v) => self.set_i32(col, *v),
+            Value::I64(v) => self.set_i64(col, *v),
+            Value::F64(v) => self.set_f64(col, *v),
+            Value::String(v) => self.set_string(col, v),
+            other => Err(format!("Cannot set column from {:?} value", other)),
+        }
+    }
 }
 
-impl Drop for Row {
+/// Converts a Rust value into a `Row` ready to `apply`/`write`.
+/// Implemented by hand, or generated by `#[derive(FlintRow)]`.
+pub trait ToRow {
+    fn to_row(&self, mt: &mut Meta) -> Result<Row<'static>, String>;
+}
+
+/// Reads a Rust value back out of a `Row`. Implemented by hand, or
+/// generated by `#[derive(FlintRow)]`.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row<'_>, mt: &mut Meta) -> Result<Self, String>;
+}
+
+/// Untyped column value returned by `Row::get_variant`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    I32(i32),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Ipv6(std::net::Ipv6Addr),
+    #[cfg(feature = "chrono")]
+    Date(chrono::NaiveDate),
+    #[cfg(feature = "chrono")]
+    Time(chrono::NaiveTime),
+    #[cfg(feature = "uuid")]
+    Uuid(uuid::Uuid),
+    #[cfg(feature = "rust_decimal")]
+    Decimal(rust_decimal::Decimal),
+}
+
+impl<'a> Drop for Row<'a> {
     fn drop(&mut self) {
         // Only free if we own the row
-        if self.owned {
+        if let Ownership::Owned = self.ownership {
             unsafe {
                 if let Some(free_fn) = (*self.ptr).free {
                     free_fn(self.ptr);
@@ -393,6 +1006,22 @@ impl Drop for Row {
     }
 }
 
+/// Sort direction for a `Table::filesort` key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    fn as_raw(self) -> i32 {
+        match self {
+            SortOrder::Asc => 0,
+            SortOrder::Desc => 1,
+        }
+    }
+}
+
 pub struct CursorI64 {
     ptr: *mut flintdb_cursor_i64,
 }
@@ -417,6 +1046,51 @@ impl CursorI64 {
     }
 }
 
+impl Iterator for CursorI64 {
+    type Item = Result<i64, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match CursorI64::next(self) {
+            Ok(Some(rowid)) => Some(Ok(rowid)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl CursorI64 {
+    /// Adapt this cursor into an iterator of rows, reading each rowid
+    /// through `tbl` as it's produced. `Row` items borrow from `tbl`
+    /// (not from the cursor), so unlike `CursorRow` this can be a plain
+    /// `std::iter::Iterator` - nothing needs to stay alive past `next()`
+    /// other than the table itself.
+    pub fn rows(self, tbl: &Table) -> Rows<'_> {
+        Rows {
+            cursor: self,
+            table: tbl,
+        }
+    }
+}
+
+/// Lazily reads a `Row` for each rowid yielded by a `CursorI64`. Built by
+/// `CursorI64::rows`.
+pub struct Rows<'a> {
+    cursor: CursorI64,
+    table: &'a Table,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Result<Row<'a>, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.cursor.next() {
+            Ok(Some(rowid)) => Some(self.table.read(rowid)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
 impl Drop for CursorI64 {
     fn drop(&mut self) {
         unsafe {
@@ -432,7 +1106,16 @@ pub struct CursorRow {
 }
 
 impl CursorRow {
-    pub fn next(&mut self) -> Result<Option<Row>, String> {
+    // Note: `CursorRow` intentionally does not implement
+    // `std::iter::Iterator`. Each `Row` it yields borrows the cursor's
+    // own reused row buffer (see `Row::borrowed` above), so its lifetime
+    // is tied to this particular call's `&mut self` - `Iterator::Item`
+    // can't express that without a lending/GAT-based iterator. Use
+    // `while let Some(row) = cursor.next()? { ... }` instead; for the
+    // common case of iterating `Table` rowids, `CursorI64::rows` returns
+    // a real `Iterator` because its rows borrow the `Table`, not the
+    // cursor.
+    pub fn next(&mut self) -> Result<Option<Row<'_>>, String> {
         let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
         let cursor = unsafe { &mut *self.ptr };
         let row_ptr = unsafe {
@@ -446,7 +1129,8 @@ impl CursorRow {
         if row_ptr.is_null() {
             Ok(None)
         } else {
-            // Return borrowed row - cursor owns it, don't free
+            // Return a row borrowing from the cursor - it owns the
+            // memory and will outlive it only as long as this `&mut self`.
             Ok(Some(unsafe { Row::borrowed(row_ptr) }))
         }
     }
@@ -472,6 +1156,322 @@ pub fn get_column_index(mt: &mut Meta, name: &str) -> u16 {
     unsafe { flintdb_column_at(mt.as_mut_ptr(), c_name.as_ptr()) as u16 }
 }
 
+fn read_columns(mt: &Meta) -> Result<Vec<(String, flintdb_variant_type)>, String> {
+    let count = mt.column_count()?;
+    (0..count)
+        .map(|col| Ok((mt.column_name(col)?, mt.column_type(col)?)))
+        .collect()
+}
+
+// Renders a `Value` as plain text, for formats (Csv) whose fields are
+// untyped strings on disk.
+fn value_to_text(value: &Value) -> Result<String, String> {
+    match value {
+        Value::Null => Ok(String::new()),
+        Value::I32(v) => Ok(v.to_string()),
+        Value::I64(v) => Ok(v.to_string()),
+        Value::F64(v) => Ok(v.to_string()),
+        Value::String(v) => Ok(v.clone()),
+        other => Err(format!("Cannot render {:?} as a Csv field", other)),
+    }
+}
+
+// Parses a field's text back into a typed `Value` given the column's
+// declared VARIANT type. An empty field is treated as NULL.
+fn value_from_text(raw: &str, vtype: flintdb_variant_type) -> Result<Value, String> {
+    if raw.is_empty() {
+        return Ok(Value::Null);
+    }
+    match vtype {
+        VAR_INT32 => raw.parse::<i32>().map(Value::I32).map_err(|e| e.to_string()),
+        VAR_INT64 => raw.parse::<i64>().map(Value::I64).map_err(|e| e.to_string()),
+        VAR_DOUBLE => raw.parse::<f64>().map(Value::F64).map_err(|e| e.to_string()),
+        VAR_STRING => Ok(Value::String(raw.to_string())),
+        other => Err(format!("Unsupported column type {} for this format", other)),
+    }
+}
+
+fn csv_encode_field(field: &str, delimiter: char, quote: char) -> Result<String, String> {
+    let needs_quoting =
+        field.contains(delimiter) || field.contains(quote) || field.contains('\n') || field.contains('\r');
+    if !needs_quoting {
+        return Ok(field.to_string());
+    }
+    let escaped = field.replace(quote, &format!("{0}{0}", quote));
+    Ok(format!("{0}{1}{0}", quote, escaped))
+}
+
+// Splits CSV text into records of raw field text, honoring quoted
+// fields that contain the delimiter, the quote character (doubled), or
+// an embedded \n or \r.
+fn split_csv_records(content: &str, delimiter: char, quote: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == quote {
+                if chars.peek() == Some(&quote) {
+                    field.push(quote);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == quote && field.is_empty() {
+            in_quotes = true;
+        } else if c == delimiter {
+            record.push(std::mem::take(&mut field));
+        } else if c == '\n' {
+            record.push(std::mem::take(&mut field));
+            records.push(std::mem::take(&mut record));
+        } else if c != '\r' {
+            field.push(c);
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+fn json_encode_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_encode_value(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::I32(v) => v.to_string(),
+        Value::I64(v) => v.to_string(),
+        Value::F64(v) => v.to_string(),
+        Value::String(v) => json_encode_string(v),
+        other => json_encode_string(&format!("{:?}", other)),
+    }
+}
+
+// Parses one flat `{"col": value, ...}` JSON object line into raw-value-
+// text fields, in column order. `raw` is the unescaped string contents
+// for string values, or the literal text for numbers/null, ready to be
+// handed to `value_from_text`. Each key is validated against `columns` at
+// the matching position - JsonLines always writes keys in column order,
+// so a key mismatch means this line came from somewhere else (or the
+// schema changed) and the line is rejected rather than silently
+// misassigned.
+fn parse_json_object_line(
+    line: &str,
+    columns: &[(String, flintdb_variant_type)],
+) -> Result<Vec<String>, String> {
+    let line = line.trim();
+    let inner = line
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(|| format!("Malformed JSON Lines record: {}", line))?;
+
+    let mut fields = Vec::new();
+    let mut chars = inner.chars().peekable();
+    while chars.peek().is_some() {
+        while chars.peek() == Some(&',') || chars.peek() == Some(&' ') {
+            chars.next();
+        }
+        if chars.peek().is_none() {
+            break;
+        }
+        let key = json_parse_string(&mut chars)?;
+        let expected = columns.get(fields.len()).map(|(name, _)| name.as_str());
+        if expected != Some(key.as_str()) {
+            return Err(format!(
+                "JSON Lines key `{}` at position {} does not match column `{}`",
+                key,
+                fields.len(),
+                expected.unwrap_or("<none>")
+            ));
+        }
+        while chars.peek() == Some(&':') || chars.peek() == Some(&' ') {
+            chars.next();
+        }
+        let value = if chars.peek() == Some(&'"') {
+            json_parse_string(&mut chars)?
+        } else {
+            let mut raw = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == ',' || c == '}' {
+                    break;
+                }
+                raw.push(c);
+                chars.next();
+            }
+            let raw = raw.trim();
+            if raw == "null" {
+                String::new()
+            } else {
+                raw.to_string()
+            }
+        };
+        fields.push(value);
+    }
+    Ok(fields)
+}
+
+fn json_parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    if chars.next() != Some('"') {
+        return Err("Expected a quoted JSON string".to_string());
+    }
+    let mut out = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(out),
+            Some('\\') => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('r') => out.push('\r'),
+                Some('t') => out.push('\t'),
+                Some(c) => out.push(c),
+                None => return Err("Unterminated JSON escape".to_string()),
+            },
+            Some(c) => out.push(c),
+            None => return Err("Unterminated JSON string".to_string()),
+        }
+    }
+}
+
+const BACKUP_PAGE_SIZE: usize = 4096;
+
+// Bounces a native backup progress callback back into the
+// `Option<&mut dyn FnMut(u64, u64)>` that `userdata` points at.
+extern "C" fn backup_progress_trampoline(
+    pages_done: u64,
+    pages_total: u64,
+    userdata: *mut ::std::os::raw::c_void,
+) {
+    if userdata.is_null() {
+        return;
+    }
+    let progress = unsafe { &mut *(userdata as *mut Option<&mut dyn FnMut(u64, u64)>) };
+    if let Some(callback) = progress {
+        callback(pages_done, pages_total);
+    }
+}
+
+// Holds the table's native read lock (when the C core exposes one) for
+// the duration of the `chunked_copy` fallback in `Table::backup`, so a
+// concurrent `apply`/`update`/`delete` can't interleave a torn write into
+// the destination file. A no-op guard (`acquire` returns `None`) when the
+// core has no `rlock`/`runlock` pair.
+struct TableReadGuard<'a> {
+    tbl: &'a Table,
+}
+
+impl<'a> TableReadGuard<'a> {
+    fn acquire(tbl: &'a Table) -> Result<Option<Self>, String> {
+        let raw = unsafe { &*tbl.ptr };
+        if let Some(rlock_fn) = raw.rlock {
+            let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+            unsafe { rlock_fn(tbl.ptr, &mut err) };
+            check_error(err)?;
+            Ok(Some(TableReadGuard { tbl }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a> Drop for TableReadGuard<'a> {
+    fn drop(&mut self) {
+        let raw = unsafe { &*self.tbl.ptr };
+        if let Some(runlock_fn) = raw.runlock {
+            let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+            unsafe { runlock_fn(self.tbl.ptr, &mut err) };
+            let _ = check_error(err);
+        }
+    }
+}
+
+// Holds the file's native read lock (when the C core exposes one) for
+// the duration of the `chunked_copy` fallback in `GenericFile::backup`,
+// mirroring `TableReadGuard` above. A no-op guard (`acquire` returns
+// `None`) when the core has no `rlock`/`runlock` pair.
+struct GenericFileReadGuard<'a> {
+    file: &'a GenericFile,
+}
+
+impl<'a> GenericFileReadGuard<'a> {
+    fn acquire(file: &'a GenericFile) -> Result<Option<Self>, String> {
+        let raw = unsafe { &*file.ptr };
+        if let Some(rlock_fn) = raw.rlock {
+            let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+            unsafe { rlock_fn(file.ptr, &mut err) };
+            check_error(err)?;
+            Ok(Some(GenericFileReadGuard { file }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a> Drop for GenericFileReadGuard<'a> {
+    fn drop(&mut self) {
+        let raw = unsafe { &*self.file.ptr };
+        if let Some(runlock_fn) = raw.runlock {
+            let mut err: *mut ::std::os::raw::c_char = ptr::null_mut();
+            unsafe { runlock_fn(self.file.ptr, &mut err) };
+            let _ = check_error(err);
+        }
+    }
+}
+
+// Page-sized copy fallback for `backup`, used when the C core doesn't
+// expose a native snapshot primitive for the handle. Callers are
+// expected to hold any native read lock for the duration - see
+// `TableReadGuard` in `Table::backup`.
+fn chunked_copy(
+    src_path: &str,
+    dest_path: &str,
+    mut progress: Option<&mut dyn FnMut(u64, u64)>,
+) -> Result<(), String> {
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    let mut src = File::open(src_path).map_err(|e| e.to_string())?;
+    let mut dest = File::create(dest_path).map_err(|e| e.to_string())?;
+
+    let total_bytes = src.metadata().map_err(|e| e.to_string())?.len();
+    let pages_total = total_bytes.div_ceil(BACKUP_PAGE_SIZE as u64).max(1);
+
+    let mut buf = vec![0u8; BACKUP_PAGE_SIZE];
+    let mut pages_done: u64 = 0;
+    loop {
+        let n = src.read(&mut buf).map_err(|e| e.to_string())?;
+        if n == 0 {
+            break;
+        }
+        dest.write_all(&buf[..n]).map_err(|e| e.to_string())?;
+        pages_done += 1;
+        if let Some(callback) = progress.as_deref_mut() {
+            callback(pages_done, pages_total);
+        }
+    }
+    Ok(())
+}
+
 fn check_error(err: *mut ::std::os::raw::c_char) -> Result<(), String> {
     if !err.is_null() {
         let c_str = unsafe { CStr::from_ptr(err) };
@@ -486,3 +1486,76 @@ fn check_error(err: *mut ::std::os::raw::c_char) -> Result<(), String> {
 pub fn cleanup() {
     unsafe { flintdb_cleanup(ptr::null_mut()) };
 }
+
+#[cfg(test)]
+mod csv_format_tests {
+    use super::*;
+
+    fn round_trip(field: &str) -> String {
+        let encoded = csv_encode_field(field, ',', '"').unwrap();
+        let line = format!("{}\n", encoded);
+        let records = split_csv_records(&line, ',', '"');
+        records[0][0].clone()
+    }
+
+    #[test]
+    fn round_trips_a_plain_field() {
+        assert_eq!(round_trip("plain"), "plain");
+    }
+
+    #[test]
+    fn round_trips_an_embedded_quote() {
+        assert_eq!(round_trip("say \"hi\""), "say \"hi\"");
+    }
+
+    #[test]
+    fn round_trips_an_embedded_delimiter() {
+        assert_eq!(round_trip("a,b"), "a,b");
+    }
+
+    #[test]
+    fn round_trips_an_embedded_newline() {
+        assert_eq!(round_trip("before\nafter"), "before\nafter");
+    }
+
+    #[test]
+    fn round_trips_a_lone_carriage_return() {
+        assert_eq!(round_trip("before\rafter"), "before\rafter");
+    }
+
+    #[test]
+    fn round_trips_a_crlf_pair() {
+        assert_eq!(round_trip("before\r\nafter"), "before\r\nafter");
+    }
+}
+
+#[cfg(test)]
+mod json_lines_format_tests {
+    use super::*;
+
+    fn columns() -> Vec<(String, flintdb_variant_type)> {
+        vec![
+            ("id".to_string(), VAR_INT32),
+            ("name".to_string(), VAR_STRING),
+        ]
+    }
+
+    #[test]
+    fn parses_fields_in_declared_column_order() {
+        let fields = parse_json_object_line(r#"{"id": 1, "name": "Widget"}"#, &columns()).unwrap();
+        assert_eq!(fields, vec!["1".to_string(), "Widget".to_string()]);
+    }
+
+    #[test]
+    fn rejects_a_key_that_does_not_match_the_column_at_that_position() {
+        let err = parse_json_object_line(r#"{"name": "Widget", "id": 1}"#, &columns()).unwrap_err();
+        assert!(err.contains("name"));
+        assert!(err.contains("id"));
+    }
+
+    #[test]
+    fn rejects_a_foreign_key_unknown_to_the_schema() {
+        let err = parse_json_object_line(r#"{"sku": 1, "name": "Widget"}"#, &columns()).unwrap_err();
+        assert!(err.contains("sku"));
+    }
+}
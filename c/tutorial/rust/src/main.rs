@@ -61,12 +61,12 @@ fn tutorial_table_find() -> Result<(), String> {
 
     // 2. Find data using a WHERE clause
     println!("Finding rows where age >= 31:");
-    let mut cursor = tbl.find("WHERE age >= 31")?;
+    let cursor = tbl.find("WHERE age >= 31")?;
 
-    // 3. Iterate through the cursor to get rowids
-    while let Some(rowid) = cursor.next()? {
-        let row = tbl.read(rowid)?;
-        print_row_safe(row.ptr);
+    // 3. Adapt the rowid cursor into a row iterator and use it like any
+    // other `Iterator`.
+    for row in cursor.rows(&tbl) {
+        print_row_safe(row?.ptr);
     }
 
     println!("\nSuccessfully found and read data.\n");
@@ -86,7 +86,7 @@ fn tutorial_tsv_create() -> Result<(), String> {
     mt.add_column("price", VAR_DOUBLE, 0, 0, 1, "", "")?;
 
     // 2. Open the generic file with the TSV format
-    let mut f = GenericFile::open(filepath, RDWR, Some(&mt))?;
+    let mut f = GenericFile::open(filepath, RDWR, Some(&mut mt), Format::Tsv)?;
 
     // 3. Write data rows
     println!("Writing 3 rows to TSV...");
@@ -112,7 +112,7 @@ fn tutorial_tsv_find() -> Result<(), String> {
     let filepath = "./temp/tutorial_products.tsv";
 
     // 1. Open the TSV file in read-only mode
-    let mut f = GenericFile::open(filepath, RDONLY, None)?;
+    let mut f = GenericFile::open(filepath, RDONLY, None, Format::Tsv)?;
 
     // 2. Find rows matching the WHERE clause
     println!("Reading rows where product_id >= 102:");
@@ -140,17 +140,36 @@ fn tutorial_table_update_delete() -> Result<(), String> {
     let mut cursor = tbl.find("WHERE age = 30")?;
 
     if let Some(rowid) = cursor.next()? {
-        let old_row = tbl.read(rowid)?;
-        println!("Before update:");
-        print_row_safe(old_row.ptr);
+        {
+            let old_row = tbl.read(rowid)?;
+            println!("Before update:");
+            print_row_safe(old_row.ptr);
+        }
 
-        // Note: Full update implementation requires apply_at function
-        println!("(Update operations require additional binding implementation)");
+        let mut mt = Meta::new(tablename)?;
+        mt.add_column("id", VAR_INT64, 0, 0, 1, "0", "PRIMARY KEY")?;
+        mt.add_column("name", VAR_STRING, 50, 0, 1, "", "Customer name")?;
+        mt.add_column("age", VAR_INT32, 0, 0, 1, "0", "Customer age")?;
+        let mut new_row = Row::new(&mut mt)?;
+        new_row.set_i64(0, rowid)?;
+        new_row.set_string(1, "Customer 1 (updated)")?;
+        new_row.set_i32(2, 31)?;
+        tbl.update(rowid, &mut new_row)?;
+
+        let updated_row = tbl.read(rowid)?;
+        println!("After update:");
+        print_row_safe(updated_row.ptr);
     }
     drop(cursor);
 
     // 3. Delete a row
-    println!("\nDelete operations require additional binding implementation");
+    println!("\nDeleting Customer with age = 32:");
+    let mut cursor3 = tbl.find("WHERE age = 32")?;
+    if let Some(rowid) = cursor3.next()? {
+        tbl.delete(rowid)?;
+        println!("Deleted rowid {}", rowid);
+    }
+    drop(cursor3);
 
     // 4. Show remaining customers
     println!("\nCurrent customers:");
@@ -164,9 +183,183 @@ fn tutorial_table_update_delete() -> Result<(), String> {
     Ok(())
 }
 
+#[derive(FlintRow)]
+struct Customer {
+    id: i64,
+    name: String,
+    age: i32,
+}
+
+fn tutorial_derive_roundtrip() -> Result<(), String> {
+    println!("--- Running tutorial_derive_roundtrip ---");
+
+    let tablename = "./temp/tutorial_customer.flintdb";
+    let mut mt = Meta::new(tablename)?;
+    mt.add_column("id", VAR_INT64, 0, 0, 1, "0", "PRIMARY KEY")?;
+    mt.add_column("name", VAR_STRING, 50, 0, 1, "", "Customer name")?;
+    mt.add_column("age", VAR_INT32, 0, 0, 1, "0", "Customer age")?;
+
+    let mut tbl = Table::open(tablename, RDWR, None)?;
+
+    // 1. Insert a struct directly via its generated `ToRow` impl.
+    let customer = Customer {
+        id: 4,
+        name: "Customer 4 (via derive)".to_string(),
+        age: 40,
+    };
+    let rowid = tbl.apply_row(&mut mt, &customer)?;
+
+    // 2. Read it back via the generated `FromRow` impl.
+    let row = tbl.read(rowid)?;
+    let round_tripped: Customer = row.into_struct(&mut mt)?;
+    println!(
+        "Round-tripped via #[derive(FlintRow)]: id={} name={} age={}",
+        round_tripped.id, round_tripped.name, round_tripped.age
+    );
+
+    println!("\nSuccessfully round-tripped a struct through FlintRow.\n");
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+fn tutorial_chrono_roundtrip() -> Result<(), String> {
+    use chrono::Timelike;
+
+    println!("--- Running tutorial_chrono_roundtrip ---");
+
+    let tablename = "./temp/tutorial_chrono.flintdb";
+    let _ = Table::drop_table(tablename);
+
+    let mut mt = Meta::new(tablename)?;
+    mt.add_column("id", VAR_INT64, 0, 0, 1, "0", "PRIMARY KEY")?;
+    mt.add_column("event_date", VAR_DATE, 0, 0, 1, "0", "Event date")?;
+    mt.add_column("event_time", VAR_TIME, 0, 0, 1, "0", "Event time")?;
+
+    let mut tbl = Table::open(tablename, RDWR, Some(&mt))?;
+
+    let date = chrono::NaiveDate::from_ymd_opt(2026, 7, 26).unwrap();
+    let time = chrono::NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+
+    let mut r = Row::new(&mut mt)?;
+    r.set_i64(0, 1)?;
+    r.set_i64(1, (date - epoch).num_days())?;
+    r.set_i64(2, time.num_seconds_from_midnight() as i64)?;
+    tbl.apply(&mut r)?;
+
+    let row = tbl.read(1)?;
+    println!("Read back: date={} time={}", row.get_date(1)?, row.get_time(2)?);
+
+    println!("\nSuccessfully round-tripped chrono date/time columns.\n");
+    Ok(())
+}
+
+#[cfg(feature = "uuid")]
+fn tutorial_uuid_roundtrip() -> Result<(), String> {
+    println!("--- Running tutorial_uuid_roundtrip ---");
+
+    let tablename = "./temp/tutorial_uuid.flintdb";
+    let _ = Table::drop_table(tablename);
+
+    let mut mt = Meta::new(tablename)?;
+    mt.add_column("id", VAR_INT64, 0, 0, 1, "0", "PRIMARY KEY")?;
+    mt.add_column("external_id", VAR_UUID, 16, 0, 1, "", "External UUID")?;
+
+    let mut tbl = Table::open(tablename, RDWR, Some(&mt))?;
+
+    let external_id = uuid::Uuid::from_bytes([
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10,
+    ]);
+    let mut r = Row::new(&mut mt)?;
+    r.set_i64(0, 1)?;
+    r.set_bytes(1, external_id.as_bytes())?;
+    tbl.apply(&mut r)?;
+
+    let row = tbl.read(1)?;
+    println!("Read back: external_id={}", row.get_uuid(1)?);
+
+    println!("\nSuccessfully round-tripped a uuid column.\n");
+    Ok(())
+}
+
+#[cfg(feature = "rust_decimal")]
+fn tutorial_decimal_roundtrip() -> Result<(), String> {
+    println!("--- Running tutorial_decimal_roundtrip ---");
+
+    let tablename = "./temp/tutorial_decimal.flintdb";
+    let _ = Table::drop_table(tablename);
+
+    let mut mt = Meta::new(tablename)?;
+    mt.add_column("id", VAR_INT64, 0, 0, 1, "0", "PRIMARY KEY")?;
+    mt.add_column("amount", VAR_DECIMAL, 10, 2, 1, "0", "Order amount")?;
+
+    let mut tbl = Table::open(tablename, RDWR, Some(&mt))?;
+
+    let mut r = Row::new(&mut mt)?;
+    r.set_i64(0, 1)?;
+    r.set_string(1, "19.99")?;
+    tbl.apply(&mut r)?;
+
+    let row = tbl.read(1)?;
+    println!("Read back: amount={}", row.get_decimal(1)?);
+
+    println!("\nSuccessfully round-tripped a rust_decimal column.\n");
+    Ok(())
+}
+
 fn tutorial_filesort() -> Result<(), String> {
-    println!("--- Filesort feature available in C API ---");
-    println!("(Rust bindings for filesort require additional implementation)\n");
+    println!("--- Running tutorial_filesort ---");
+
+    let tablename = "./temp/tutorial_customer.flintdb";
+    let mut tbl = Table::open(tablename, RDONLY, None)?;
+
+    println!("Customers sorted by age (descending):");
+    let mut cursor = tbl.filesort(&[("age", SortOrder::Desc)])?;
+    while let Some(rowid) = cursor.next()? {
+        let row = tbl.read(rowid)?;
+        print_row_safe(row.ptr);
+    }
+
+    println!("\nSuccessfully filesorted customers.\n");
+    Ok(())
+}
+
+fn tutorial_csv_roundtrip() -> Result<(), String> {
+    println!("--- Running tutorial_csv_roundtrip ---");
+
+    let filepath = "./temp/tutorial_products.csv";
+    let _ = GenericFile::drop_file(filepath);
+
+    let mut mt = Meta::new(filepath)?;
+    mt.add_column("product_id", VAR_INT32, 0, 0, 1, "", "")?;
+    mt.add_column("product_name", VAR_STRING, 100, 0, 1, "", "")?;
+    mt.add_column("price", VAR_DOUBLE, 0, 0, 1, "", "")?;
+
+    let format = Format::Csv {
+        delimiter: ',',
+        quote: '"',
+    };
+    let mut f = GenericFile::open(filepath, RDWR, Some(&mut mt), format.clone())?;
+
+    println!("Writing rows with quoting edge cases to CSV...");
+    let mut r = Row::new(&mut mt)?;
+    r.set_i32(get_column_index(&mut mt, "product_id"), 201)?;
+    r.set_string(get_column_index(&mut mt, "product_name"), "Widget, Deluxe \"Pro\"")?;
+    r.set_f64(get_column_index(&mut mt, "price"), 19.99)?;
+    f.write(&mut r)?;
+    drop(f);
+
+    // Reopen so the native mirror behind `find` is rebuilt by parsing
+    // the CSV text that was just written.
+    let mut f = GenericFile::open(filepath, RDONLY, Some(&mut mt), format)?;
+
+    println!("Reading rows where product_id >= 201 back from CSV:");
+    let mut cursor = f.find("WHERE product_id >= 201")?;
+    while let Some(row) = cursor.next()? {
+        print_row_safe(row.ptr);
+    }
+
+    println!("\nSuccessfully round-tripped data through the CSV format.\n");
     Ok(())
 }
 
@@ -192,6 +385,11 @@ fn main() {
         return;
     }
 
+    if let Err(e) = tutorial_derive_roundtrip() {
+        eprintln!("Error in tutorial_derive_roundtrip: {}", e);
+        return;
+    }
+
     if let Err(e) = tutorial_tsv_create() {
         eprintln!("Error in tutorial_tsv_create: {}", e);
         return;
@@ -207,6 +405,29 @@ fn main() {
         return;
     }
 
+    if let Err(e) = tutorial_csv_roundtrip() {
+        eprintln!("Error in tutorial_csv_roundtrip: {}", e);
+        return;
+    }
+
+    #[cfg(feature = "chrono")]
+    if let Err(e) = tutorial_chrono_roundtrip() {
+        eprintln!("Error in tutorial_chrono_roundtrip: {}", e);
+        return;
+    }
+
+    #[cfg(feature = "uuid")]
+    if let Err(e) = tutorial_uuid_roundtrip() {
+        eprintln!("Error in tutorial_uuid_roundtrip: {}", e);
+        return;
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    if let Err(e) = tutorial_decimal_roundtrip() {
+        eprintln!("Error in tutorial_decimal_roundtrip: {}", e);
+        return;
+    }
+
     println!("All tutorial steps completed successfully.");
     
     // Cleanup will be called automatically when _cleanup goes out of scope